@@ -31,8 +31,35 @@
 //!     <Icon icon=icondata::BsFolder />
 //! };
 //! ```
+//!
+//! # Switching icons reactively
+//!
+//! The `icon` prop accepts any signal, so there is no need for conditional rendering when an icon
+//! depends on state. The rendered `<svg>` is replaced whenever the signal changes:
+//! ```
+//! use leptos::*;
+//! use leptos_icons::*;
+//!
+//! let (playing, set_playing) = create_signal(false);
+//! let icon = Signal::derive(move || {
+//!     if playing.get() {
+//!         icondata::BsPause
+//!     } else {
+//!         icondata::BsPlay
+//!     }
+//! });
+//!
+//! # #[cfg(target_arch = "wasm32")]
+//! let _ = view! {
+//!     <button on:click=move |_| set_playing.update(|playing| *playing = !*playing)>
+//!         <Icon icon />
+//!     </button>
+//! };
+//! ```
 //! [__Complete examples__](https://github.com/Carlosted/leptos-icons/tree/main/examples) are available on github.
 
+use std::fmt::Write;
+
 use leptos::*;
 
 /// The Icon component.
@@ -53,14 +80,75 @@ pub fn Icon(
     /// HTML style attribute.
     #[prop(into, optional)]
     style: MaybeProp<TextProp>,
+    /// Start and end colors of a linear gradient painting the icon instead of its fill
+    /// (or its stroke, for outline icons). Parts of an icon with their own colors are unaffected.
+    #[prop(into, optional)]
+    gradient: MaybeProp<(String, String)>,
+    /// The id of the gradient element. Defaults to one derived from the hydration key, which isn't
+    /// unique in trees that are never hydrated (outside of islands): set it there when using `gradient`.
+    #[prop(into, optional)]
+    gradient_id: MaybeProp<TextProp>,
+    /// Accessible name of the icon, set as `aria-label`. Required by `interactive`.
+    #[prop(into, optional)]
+    label: MaybeProp<TextProp>,
+    /// Makes a labelled icon focusable (`role="button"`, `tabindex`) with the
+    /// `leptos-icons-interactive` class for `:focus-visible` styling. Svg elements don't activate
+    /// on Enter or Space: handle `keydown` and `click` on an ancestor element, both events bubble.
+    /// Without a `label` the icon stays non-interactive. Other icons are `aria-hidden` unless
+    /// given a `role` or `label`. Defaults to false.
+    #[prop(into, optional)]
+    interactive: MaybeSignal<bool>,
+    /// Position of an interactive icon in the tab order. Defaults to 0, ignored on other icons.
+    #[prop(into, optional)]
+    tabindex: MaybeProp<i32>,
+    /// HTML role attribute. An empty value omits the attribute. Combined with `interactive` and `label`:
+    /// - unset: "button" when interactive, otherwise "graphics-symbol", `aria-hidden` without a label.
+    /// - set: used as given (or omitted when empty), and the icon is never `aria-hidden`.
+    ///
+    /// An exposed icon is only named by its `label`: roles like "img" should always come with one.
+    #[prop(into, optional)]
+    role: MaybeProp<TextProp>,
+    /// Content rendered inside the root `<svg>`, after the icon itself (e.g. a badge).
+    /// Coordinates and sizes are in the icon's viewBox units, not in CSS pixels.
+    #[prop(optional)]
+    children: Option<ChildrenFn>,
 ) -> impl IntoView
 where
 {
     let svg = move || {
         let icon = icon.get();
+        let label = label.get().map(|label| label.get());
+        let interactive = interactive.get();
+        if interactive && label.is_none() {
+            logging::debug_warn!("interactive icons need a `label`, rendering as non-interactive");
+        }
+        let interactive = interactive && label.is_some();
+        // Gradients are referenced document-wide, so each one needs its own id.
+        // A hydration key keeps that id identical on the server and on the hydrating client.
+        let gradient = gradient.get().map(|colors| {
+            let id = match gradient_id.get() {
+                Some(id) => id.get().to_string(),
+                None => {
+                    let key = leptos_dom::HydrationCtx::id()
+                        .unwrap_or_else(leptos_dom::HydrationCtx::peek_always);
+                    format!("leptos-icons-gradient-{key}")
+                }
+            };
+            (id, colors)
+        });
+        let paint = gradient.as_ref().map(|(id, _)| format!("url(#{id})"));
+        let outline = icon.fill == Some("none");
         let mut svg = svg::svg();
-        if let Some(classes) = class.get() {
-            svg = svg.classes(classes.get());
+        let classes = match (class.get(), interactive) {
+            (Some(classes), true) => {
+                Some(Oco::from(format!("{} {INTERACTIVE_CLASS}", classes.get())))
+            }
+            (Some(classes), false) => Some(classes.get()),
+            (None, true) => Some(Oco::from(INTERACTIVE_CLASS)),
+            (None, false) => None,
+        };
+        if let Some(classes) = classes {
+            svg = svg.classes(classes);
         }
         let mut svg = match (style.get(), icon.style) {
             (Some(a), Some(b)) => svg.attr("style", format!("{b} {}", a.get())),
@@ -68,12 +156,6 @@ where
             (None, Some(b)) => svg.attr("style", b),
             (None, None) => svg,
         };
-        if let Some(x) = icon.x {
-            svg = svg.attr("x", x);
-        }
-        if let Some(y) = icon.y {
-            svg = svg.attr("y", y);
-        }
         // The style set by the user overrides the style set by the icon.
         // We ignore the width and height attributes of the icon, even if the user hasn't specified any.
         svg = svg.attr(
@@ -90,25 +172,474 @@ where
                 _ => Oco::from("1em"),
             }),
         );
-        if let Some(view_box) = icon.view_box {
-            svg = svg.attr("viewBox", view_box);
+        for (name, value) in icon_attributes(icon) {
+            svg = svg.attr(name, value);
         }
-        if let Some(stroke_linecap) = icon.stroke_linecap {
-            svg = svg.attr("stroke-linecap", stroke_linecap);
+        svg = match (&paint, icon.stroke) {
+            (Some(paint), _) if outline => svg.attr("stroke", paint.clone()),
+            (_, Some(stroke)) => svg.attr("stroke", stroke),
+            _ => svg,
+        };
+        svg = match paint {
+            Some(paint) if !outline => svg.attr("fill", paint),
+            _ => svg.attr("fill", icon.fill.unwrap_or("currentColor")),
+        };
+        let role = role.get().map(|role| role.get());
+        for (name, value) in a11y_attributes(interactive, role, label, tabindex.get()) {
+            svg = svg.attr(name, Attribute::String(value));
         }
-        if let Some(stroke_linejoin) = icon.stroke_linejoin {
-            svg = svg.attr("stroke-linejoin", stroke_linejoin);
+        if gradient.is_none() && children.is_none() {
+            return svg.inner_html(icon.data);
         }
-        if let Some(stroke_width) = icon.stroke_width {
-            svg = svg.attr("stroke-width", stroke_width);
+        // The icon body can't be set with inner_html next to other child nodes, so it gets a group.
+        if let Some((id, (start, end))) = gradient {
+            svg = svg.child(
+                svg::defs().child(
+                    svg::linearGradient()
+                        .attr("id", id)
+                        .child(svg::stop().attr("offset", "0%").attr("stop-color", start))
+                        .child(svg::stop().attr("offset", "100%").attr("stop-color", end)),
+                ),
+            );
         }
-        if let Some(stroke) = icon.stroke {
-            svg = svg.attr("stroke", stroke);
+        svg = svg.child(svg::g().inner_html(icon.data));
+        if let Some(children) = &children {
+            svg = svg.child(children());
         }
-        svg = svg.attr("fill", icon.fill.unwrap_or("currentColor"));
-        svg = svg.attr("role", "graphics-symbol");
-        svg = svg.inner_html(icon.data);
         svg
     };
     IntoView::into_view(svg)
 }
+
+/// The attributes taken as-is from the icon data.
+fn icon_attributes(
+    icon: icondata_core::Icon,
+) -> impl Iterator<Item = (&'static str, &'static str)> {
+    [
+        ("x", icon.x),
+        ("y", icon.y),
+        ("viewBox", icon.view_box),
+        ("stroke-linecap", icon.stroke_linecap),
+        ("stroke-linejoin", icon.stroke_linejoin),
+        ("stroke-width", icon.stroke_width),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| Some((name, value?)))
+}
+
+const INTERACTIVE_CLASS: &str = "leptos-icons-interactive";
+
+/// The accessibility attributes of the root element.
+fn a11y_attributes(
+    interactive: bool,
+    role: Option<Oco<'static, str>>,
+    label: Option<Oco<'static, str>>,
+    tabindex: Option<i32>,
+) -> Vec<(&'static str, Oco<'static, str>)> {
+    // Only unlabelled icons left with the default, non-interactive role are hidden from assistive
+    // technologies.
+    let hidden = !interactive && role.is_none() && label.is_none();
+    let role = match role {
+        Some(role) if role.is_empty() => None,
+        Some(role) => Some(role),
+        None if interactive => Some(Oco::from("button")),
+        None => Some(Oco::from("graphics-symbol")),
+    };
+    let focus = if interactive {
+        vec![
+            ("tabindex", Oco::from(tabindex.unwrap_or(0).to_string())),
+            ("focusable", Oco::from("true")),
+        ]
+    } else {
+        // Legacy IE and Edge put inline svg elements in the tab order unless told otherwise,
+        // which only interactive icons should be part of.
+        vec![("focusable", Oco::from("false"))]
+    };
+    role.map(|role| ("role", role))
+        .into_iter()
+        .chain(label.map(|label| ("aria-label", label)))
+        .chain(hidden.then_some(("aria-hidden", Oco::from("true"))))
+        .chain(focus)
+        .collect()
+}
+
+/// Options applied by [`icon_to_svg_string`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SvgOptions {
+    /// The width of the icon. Defaults to "1em".
+    pub width: Option<String>,
+    /// The height of the icon. Defaults to "1em".
+    pub height: Option<String>,
+    /// The color used by icons painted with `currentColor`.
+    pub color: Option<String>,
+    /// The role attribute. Defaults to "graphics-symbol", an empty value omits the attribute.
+    pub role: Option<String>,
+    /// Accessible name of the icon, set as `aria-label`.
+    pub label: Option<String>,
+}
+
+/// Renders an icon to a standalone `<svg>` string, for use outside of a DOM (emails, PDFs, ...).
+///
+/// The root element gets the attributes of an [`Icon`] rendered with the same props. In particular
+/// the svg is `aria-hidden` unless it is given a `role` or a `label`.
+/// ```
+/// use leptos_icons::*;
+///
+/// let options = SvgOptions {
+///     width: Some("24px".to_string()),
+///     color: Some("red".to_string()),
+///     ..Default::default()
+/// };
+/// let svg = icon_to_svg_string(icondata::BsFolder, &options);
+/// assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg""#));
+/// assert!(svg.contains(r#"width="24px""#));
+/// assert!(svg.contains(r#"height="1em""#));
+/// assert!(svg.contains(r#"color="red""#));
+/// assert!(svg.contains(r#"focusable="false""#));
+/// ```
+pub fn icon_to_svg_string(icon: icondata_core::Icon, options: &SvgOptions) -> String {
+    let mut svg = String::from(r#"<svg xmlns="http://www.w3.org/2000/svg""#);
+    let mut attr = |name: &str, value: &str| {
+        // Writing into a String can't fail.
+        let _ = write!(svg, r#" {name}="{}""#, escape_attribute(value));
+    };
+    if let Some(style) = icon.style {
+        attr("style", style);
+    }
+    attr("width", options.width.as_deref().unwrap_or("1em"));
+    attr("height", options.height.as_deref().unwrap_or("1em"));
+    for (name, value) in icon_attributes(icon) {
+        attr(name, value);
+    }
+    if let Some(stroke) = icon.stroke {
+        attr("stroke", stroke);
+    }
+    attr("fill", icon.fill.unwrap_or("currentColor"));
+    if let Some(color) = &options.color {
+        attr("color", color);
+    }
+    let role = options.role.clone().map(Oco::from);
+    let label = options.label.clone().map(Oco::from);
+    for (name, value) in a11y_attributes(false, role, label, None) {
+        attr(name, &value);
+    }
+    svg.push('>');
+    svg.push_str(icon.data);
+    svg.push_str("</svg>");
+    svg
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTLINE: icondata_core::Icon = &icondata_core::IconData {
+        style: None,
+        x: None,
+        y: None,
+        width: None,
+        height: None,
+        view_box: Some("0 0 24 24"),
+        stroke_linecap: None,
+        stroke_linejoin: None,
+        stroke_width: Some("2"),
+        stroke: Some("currentColor"),
+        fill: Some("none"),
+        data: r#"<path d="M4 4h16v16H4z"></path>"#,
+    };
+
+    fn render<N: IntoView>(view: impl FnOnce() -> N + 'static) -> String {
+        leptos::ssr::render_to_string(view).to_string()
+    }
+
+    /// Checks the root `<svg>` of a default-rendered icon against the icon's own data.
+    fn assert_root_attributes(icon: icondata_core::Icon, html: &str) {
+        let root = &html[..html.find('>').unwrap()];
+        assert!(root.starts_with("<svg"));
+        assert!(root.contains(r#"width="1em""#));
+        assert!(root.contains(r#"height="1em""#));
+        let fill = icon.fill.unwrap_or("currentColor");
+        assert!(root.contains(&format!(r#"fill="{fill}""#)));
+        for (name, value) in [
+            ("viewBox", icon.view_box),
+            ("stroke", icon.stroke),
+            ("stroke-linecap", icon.stroke_linecap),
+            ("stroke-width", icon.stroke_width),
+        ] {
+            match value {
+                Some(value) => assert!(root.contains(&format!(r#" {name}="{value}""#))),
+                None => assert!(!root.contains(&format!(" {name}="))),
+            }
+        }
+    }
+
+    fn gradient_ids(html: &str) -> Vec<&str> {
+        html.match_indices(r#"id="leptos-icons-gradient-"#)
+            .map(|(index, _)| {
+                let start = index + r#"id=""#.len();
+                let end = start + html[start..].find('"').unwrap();
+                &html[start..end]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn renders_filled_icons() {
+        for icon in [icondata::BsFolder, icondata::AiCarryOutTwotone] {
+            assert!(icon.stroke.is_none());
+            let html = render(move || view! { <Icon icon /> });
+            assert_root_attributes(icon, &html);
+            assert!(html.contains(icon.data));
+        }
+    }
+
+    #[test]
+    fn renders_stroked_icons() {
+        let icon = icondata::FiHome;
+        assert!(icon.stroke_linecap.is_some() && icon.stroke_width.is_some());
+        let html = render(move || view! { <Icon icon /> });
+        assert_root_attributes(icon, &html);
+        assert!(html.contains(icon.data));
+    }
+
+    #[test]
+    fn size_props_override_the_default() {
+        let html = render(|| view! { <Icon icon=icondata::BsFolder width="2em" height="3em" /> });
+        assert!(html.contains(r#"width="2em""#));
+        assert!(html.contains(r#"height="3em""#));
+    }
+
+    #[test]
+    fn icons_are_not_focusable_by_default() {
+        let html = render(|| view! { <Icon icon=icondata::BsFolder /> });
+        assert!(html.contains(r#"focusable="false""#));
+        assert!(!html.contains("tabindex"));
+    }
+
+    #[test]
+    fn gradients_get_unique_ids() {
+        let html = render(|| {
+            view! {
+                <Icon icon=icondata::BsFolder gradient=("red".to_string(), "blue".to_string()) />
+                <Icon icon=icondata::BsFolder gradient=("red".to_string(), "blue".to_string()) />
+            }
+        });
+        let ids = gradient_ids(&html);
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        for id in ids {
+            assert!(html.contains(&format!(r#"fill="url(#{id})""#)));
+        }
+        assert!(html.contains(r#"stop-color="red""#));
+        assert!(html.contains(r#"stop-color="blue""#));
+    }
+
+    #[test]
+    fn gradient_id_can_be_set() {
+        let html = render(|| {
+            view! {
+                <Icon
+                    icon=icondata::BsFolder
+                    gradient=("red".to_string(), "blue".to_string())
+                    gradient_id="folder-gradient"
+                />
+            }
+        });
+        assert!(html.contains(r#"id="folder-gradient""#));
+        assert!(html.contains(r#"fill="url(#folder-gradient)""#));
+    }
+
+    #[test]
+    fn outline_icons_get_the_gradient_on_their_stroke() {
+        let html = render(|| {
+            view! { <Icon icon=OUTLINE gradient=("red".to_string(), "blue".to_string()) /> }
+        });
+        let id = gradient_ids(&html)[0];
+        assert!(html.contains(&format!(r#"stroke="url(#{id})""#)));
+        assert!(html.contains(r#"fill="none""#));
+    }
+
+    #[test]
+    fn gradient_colors_are_escaped() {
+        let html = render(|| {
+            let color = r#"red"/><image href=x onerror=alert(1)>"#.to_string();
+            view! { <Icon icon=icondata::BsFolder gradient=(color, "blue".to_string()) /> }
+        });
+        assert!(!html.contains("<image"));
+    }
+
+    #[test]
+    fn icons_without_gradient_are_unchanged() {
+        let html = render(|| view! { <Icon icon=icondata::BsFolder /> });
+        assert!(gradient_ids(&html).is_empty());
+        assert!(!html.contains("<defs"));
+        assert!(html.contains(r#"fill="currentColor""#));
+    }
+
+    #[test]
+    fn children_are_rendered_after_the_icon() {
+        let html = render(|| {
+            view! {
+                <Icon icon=OUTLINE>
+                    <circle cx="20" cy="4" r="4"></circle>
+                </Icon>
+            }
+        });
+        let group = html.find("<g").unwrap();
+        let path = html.find("<path").unwrap();
+        let group_end = html.find("</g>").unwrap();
+        let circle = html.find("<circle").unwrap();
+        let svg_end = html.rfind("</svg>").unwrap();
+        // The icon body is wrapped in a group, and the children follow it inside the root svg.
+        assert!(group < path && path < group_end);
+        assert!(group_end < circle && circle < svg_end);
+    }
+
+    #[test]
+    fn interactive_icons_are_focusable_buttons() {
+        let html = render(|| {
+            view! { <Icon icon=icondata::BsFolder label="Open" interactive=true /> }
+        });
+        assert!(html.contains(r#"role="button""#));
+        assert!(html.contains(r#"aria-label="Open""#));
+        assert!(html.contains(r#"tabindex="0""#));
+        assert!(html.contains(r#"focusable="true""#));
+        assert!(html.contains(r#"class="leptos-icons-interactive""#));
+        assert!(!html.contains("aria-hidden"));
+    }
+
+    #[test]
+    fn interactive_icons_need_a_label() {
+        let html = render(|| view! { <Icon icon=icondata::BsFolder interactive=true /> });
+        assert!(!html.contains("tabindex"));
+        assert!(!html.contains(r#"role="button""#));
+        assert!(html.contains(r#"aria-hidden="true""#));
+        assert!(html.contains(r#"focusable="false""#));
+    }
+
+    #[test]
+    fn interactive_icons_accept_a_tabindex() {
+        let html = render(|| {
+            view! { <Icon icon=icondata::BsFolder label="Open" interactive=true tabindex=-1 /> }
+        });
+        assert!(html.contains(r#"tabindex="-1""#));
+
+        let html = render(|| view! { <Icon icon=icondata::BsFolder tabindex=2 /> });
+        assert!(!html.contains("tabindex"));
+    }
+
+    #[test]
+    fn labelled_icons_are_exposed() {
+        let html = render(|| view! { <Icon icon=icondata::BsFolder label="Folder" /> });
+        assert!(html.contains(r#"aria-label="Folder""#));
+        assert!(html.contains(r#"role="graphics-symbol""#));
+        assert!(!html.contains("aria-hidden"));
+        assert!(!html.contains("tabindex"));
+    }
+
+    #[test]
+    fn non_interactive_icons_are_hidden() {
+        let html = render(|| view! { <Icon icon=icondata::BsFolder /> });
+        assert!(html.contains(r#"role="graphics-symbol""#));
+        assert!(html.contains(r#"aria-hidden="true""#));
+        assert!(!html.contains("leptos-icons-interactive"));
+    }
+
+    #[test]
+    fn interactive_class_is_appended_to_user_class() {
+        let html = render(|| {
+            view! { <Icon icon=icondata::BsFolder class="big" label="Open" interactive=true /> }
+        });
+        assert!(html.contains(r#"class="big leptos-icons-interactive""#));
+    }
+
+    #[test]
+    fn explicit_role_is_exposed() {
+        let html = render(|| view! { <Icon icon=icondata::BsFolder role="img" /> });
+        assert!(html.contains(r#"role="img""#));
+        assert!(!html.contains("aria-hidden"));
+        assert!(html.contains(r#"focusable="false""#));
+    }
+
+    #[test]
+    fn explicit_role_is_named_by_the_label() {
+        let html = render(|| view! { <Icon icon=icondata::BsFolder role="img" label="Folder" /> });
+        assert!(html.contains(r#"role="img""#));
+        assert!(html.contains(r#"aria-label="Folder""#));
+        assert!(!html.contains("aria-hidden"));
+    }
+
+    #[test]
+    fn empty_role_is_omitted() {
+        let html = render(|| view! { <Icon icon=icondata::BsFolder role="" /> });
+        assert!(!html.contains("role="));
+        assert!(!html.contains("aria-hidden"));
+
+        let html = render(|| {
+            view! { <Icon icon=icondata::BsFolder role="" label="Open" interactive=true /> }
+        });
+        assert!(!html.contains("role="));
+        assert!(html.contains(r#"tabindex="0""#));
+    }
+
+    #[test]
+    fn explicit_role_overrides_interactive_default() {
+        let html = render(|| {
+            view! { <Icon icon=icondata::BsFolder role="link" label="Open" interactive=true /> }
+        });
+        assert!(html.contains(r#"role="link""#));
+        assert!(!html.contains(r#"role="button""#));
+        assert!(html.contains(r#"tabindex="0""#));
+    }
+
+    #[test]
+    fn svg_string_uses_the_requested_size() {
+        let options = SvgOptions {
+            height: Some("32px".to_string()),
+            ..Default::default()
+        };
+        let svg = icon_to_svg_string(icondata::BsFolder, &options);
+        assert!(svg.contains(r#"width="1em""#));
+        assert!(svg.contains(r#"height="32px""#));
+    }
+
+    #[test]
+    fn svg_string_has_no_color_by_default() {
+        let svg = icon_to_svg_string(icondata::BsFolder, &SvgOptions::default());
+        assert!(!svg.contains("color="));
+        assert!(svg.contains(r#"fill="currentColor""#));
+        assert!(svg.contains(r#"aria-hidden="true""#));
+        assert!(svg.ends_with(&format!("{}</svg>", icondata::BsFolder.data)));
+    }
+
+    #[test]
+    fn svg_string_escapes_options() {
+        let options = SvgOptions {
+            width: Some(r#"1em" onload="alert(1)"#.to_string()),
+            ..Default::default()
+        };
+        let svg = icon_to_svg_string(icondata::BsFolder, &options);
+        assert!(svg.contains(r#"width="1em&quot; onload=&quot;alert(1)""#));
+        assert!(!svg.contains(r#"" onload=""#));
+    }
+
+    #[test]
+    fn svg_string_can_be_labelled() {
+        let options = SvgOptions {
+            role: Some("img".to_string()),
+            label: Some("Folder".to_string()),
+            ..Default::default()
+        };
+        let svg = icon_to_svg_string(icondata::BsFolder, &options);
+        assert!(svg.contains(r#"role="img""#));
+        assert!(svg.contains(r#"aria-label="Folder""#));
+        assert!(!svg.contains("aria-hidden"));
+    }
+}